@@ -1,8 +1,11 @@
 use num_traits::cast;
+use num_traits::Float;
 use num_traits::Num;
 use num_traits::NumAssign;
 use num_traits::NumCast;
 use num_traits::Zero;
+#[cfg(feature = "rand")]
+use rand::distributions::Distribution;
 
 /// Online algorithm for mean and variance, with support for uneven weights.
 ///
@@ -28,7 +31,9 @@ use num_traits::Zero;
 pub struct Welford<T, W = usize> {
     mean: Option<T>,
     total: W,
-    msq: T,
+    m2: T,
+    m3: T,
+    m4: T,
 }
 
 impl<T> Welford<T>
@@ -47,7 +52,9 @@ where
         Self {
             mean: None,
             total: 0,
-            msq: T::zero(),
+            m2: T::zero(),
+            m3: T::zero(),
+            m4: T::zero(),
         }
     }
 }
@@ -74,7 +81,9 @@ where
 {
     /// Add a new sample to the calculator.
     ///
-    /// The weight is assumed to be unity.
+    /// The weight is assumed to be unity. Unlike [`Welford::push_weighted`],
+    /// this also updates the third and fourth central moments used by
+    /// [`Welford::skewness`] and [`Welford::kurtosis`].
     ///
     /// # Examples
     /// ```
@@ -83,7 +92,89 @@ where
     /// w.push(1.0);
     /// ```
     pub fn push(&mut self, value: T) {
-        self.push_weighted(value, 1)
+        self.total += 1;
+
+        if self.mean.is_none() {
+            self.mean = Some(value);
+            return;
+        }
+
+        // self.mean is Some(T) from here on.
+        let n: T = cast(self.total).expect("failed to cast total to T");
+        let delta = value - self.mean.unwrap();
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * (n - T::one());
+
+        *self.mean.as_mut().unwrap() += delta_n;
+
+        let three: T = cast(3).expect("failed to cast to T");
+        self.m4 += term1 * delta_n2 * (n * n - three * n + three)
+            + cast::<_, T>(6).expect("failed to cast to T") * delta_n2 * self.m2
+            - cast::<_, T>(4).expect("failed to cast to T") * delta_n * self.m3;
+        let two: T = cast(2).expect("failed to cast to T");
+        self.m3 += term1 * delta_n * (n - two) - three * delta_n * self.m2;
+        self.m2 += term1;
+    }
+}
+
+impl<T> Welford<T>
+where
+    T: Float + NumAssign,
+{
+    /// Push a new sample and return its standardized z-score in one call.
+    ///
+    /// See [`Welford::push`] and [`Welford::standardize`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use welford::Welford;
+    /// let mut w = Welford::new();
+    /// w.push(1.0);
+    /// assert_eq!(w.push_standardized(3.0), Some(1.0 / 2.0_f64.sqrt()));
+    /// ```
+    pub fn push_standardized(&mut self, value: T) -> Option<T> {
+        self.push(value);
+        self.standardize(value)
+    }
+}
+
+impl<T> Extend<T> for Welford<T>
+where
+    T: Copy + Num + NumAssign + NumCast,
+{
+    /// Feed values into the calculator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use welford::Welford;
+    /// let mut w = Welford::new();
+    /// w.extend([1.0, 2.0, 3.0]);
+    /// assert_eq!(w.mean(), Some(2.0));
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for Welford<T>
+where
+    T: Copy + Num + NumAssign + NumCast,
+{
+    /// Build a calculator from an iterator of values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use welford::Welford;
+    /// let w: Welford<f64> = [1.0, 2.0, 3.0].into_iter().collect();
+    /// assert_eq!(w.mean(), Some(2.0));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut w = Self::new();
+        w.extend(iter);
+        w
     }
 }
 
@@ -104,7 +195,9 @@ where
         Self {
             mean: None,
             total: W::zero(),
-            msq: T::zero(),
+            m2: T::zero(),
+            m3: T::zero(),
+            m4: T::zero(),
         }
     }
 }
@@ -116,6 +209,10 @@ where
 {
     /// Add a new sample to the calculator with a given weight.
     ///
+    /// This does not update the third and fourth central moments, so
+    /// [`Welford::skewness`] and [`Welford::kurtosis`] are only meaningful
+    /// for calculators fed exclusively through [`Welford::push`].
+    ///
     /// # Examples
     /// ```
     /// # use welford::Welford;
@@ -138,7 +235,7 @@ where
         *self.mean.as_mut().unwrap() += weighted_delta / total;
 
         let delta2 = value - self.mean.unwrap();
-        self.msq += weighted_delta * delta2;
+        self.m2 += weighted_delta * delta2;
     }
 
     /// Get the mean of the samples so far.
@@ -155,23 +252,81 @@ where
         self.mean
     }
 
-    /// Get the variance of the samples so far.
+    /// Get the sample variance of the samples so far.
     ///
-    /// Weights are treated as
+    /// This divides by `total - 1` (Bessel's correction). Weights are
+    /// treated as
     /// [frequencies instead of reliabilities][weighted-variance].
     ///
     /// [weighted-variance]: https://en.wikipedia.org/wiki/Weighted_arithmetic_mean#Weighted_sample_variance
     pub fn var(&self) -> Option<T> {
         if self.total > W::one() {
             let total: T = cast(self.total).expect("failed to cast W to T");
-            Some(self.msq / (total - T::one()))
+            Some(self.m2 / (total - T::one()))
+        } else {
+            None
+        }
+    }
+
+    /// Alias for [`Welford::var`].
+    pub fn sample_variance(&self) -> Option<T> {
+        self.var()
+    }
+
+    /// Get the population variance of the samples so far.
+    ///
+    /// Unlike [`Welford::var`], this divides by `total` instead of
+    /// `total - 1`, i.e. it does not apply Bessel's correction.
+    pub fn population_variance(&self) -> Option<T> {
+        if self.total > W::zero() {
+            let total: T = cast(self.total).expect("failed to cast W to T");
+            Some(self.m2 / total)
         } else {
             None
         }
     }
 
+    /// Get the number of samples accumulated so far.
+    ///
+    /// # Examples
+    /// ```
+    /// # use welford::Welford;
+    /// let mut w = Welford::new();
+    /// w.push(1.0);
+    /// w.push(2.0);
+    /// assert_eq!(w.count(), 2);
+    /// ```
+    pub fn count(&self) -> W {
+        self.total
+    }
+
+    /// Alias for [`Welford::count`].
+    pub fn len(&self) -> W {
+        self.total
+    }
+
+    /// Returns `true` if no samples have been accumulated yet.
+    ///
+    /// # Examples
+    /// ```
+    /// # use welford::Welford;
+    /// let mut w = Welford::new();
+    /// assert!(w.is_empty());
+    /// w.push(1.0);
+    /// assert!(!w.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.total == W::zero()
+    }
+
     /// Merge the contents of another Welford calculator into this one.
     ///
+    /// This also combines the third and fourth central moments, following
+    /// [Chan et al.'s parallel combination
+    /// formulas](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Higher-order_statistics),
+    /// so [`Welford::skewness`] and [`Welford::kurtosis`] stay correct
+    /// across merges.
+    ///
     /// # Examples
     /// ```
     /// # use welford::Welford;
@@ -201,16 +356,199 @@ where
         let total = self.total + weight;
         let weighted_delta = delta * cast(weight).expect("failed to cast W to T");
 
-        let mean_corr = weighted_delta / cast(total).expect("failed to cast W to T");
+        let na: T = cast(self.total).expect("failed to cast W to T");
+        let nb: T = cast(weight).expect("failed to cast W to T");
+        let nab: T = cast(total).expect("failed to cast W to T");
+
+        let mean_corr = weighted_delta / nab;
         *self.mean.as_mut().unwrap() += mean_corr;
 
-        self.msq +=
-            other.msq + delta * cast(self.total).expect("failed to cast W to T") * mean_corr;
+        let delta2 = delta * delta;
+        let delta3 = delta2 * delta;
+        let delta4 = delta2 * delta2;
+
+        let six: T = cast(6).expect("failed to cast to T");
+        let four: T = cast(4).expect("failed to cast to T");
+        self.m4 += other.m4
+            + delta4 * na * nb * (na * na - na * nb + nb * nb) / (nab * nab * nab)
+            + six * delta2 * (na * na * other.m2 + nb * nb * self.m2) / (nab * nab)
+            + four * delta * (na * other.m3 - nb * self.m3) / nab;
+        let three: T = cast(3).expect("failed to cast to T");
+        self.m3 += other.m3 + delta3 * na * nb * (na - nb) / (nab * nab)
+            + three * delta * (na * other.m2 - nb * self.m2) / nab;
+        self.m2 += other.m2 + delta * na * mean_corr;
 
         self.total = total;
     }
 }
 
+impl<T, W> Extend<(T, W)> for Welford<T, W>
+where
+    T: Copy + Num + NumAssign + NumCast,
+    W: Copy + Num + NumAssign + NumCast + PartialOrd,
+{
+    /// Feed value/weight pairs into the calculator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use welford::Welford;
+    /// let mut w = Welford::with_weights();
+    /// w.extend([(1.0, 3), (3.0, 2), (5.0, 1)]);
+    /// assert_eq!(w.mean(), Some(2.333_333_333_333_333_5));
+    /// ```
+    fn extend<I: IntoIterator<Item = (T, W)>>(&mut self, iter: I) {
+        for (value, weight) in iter {
+            self.push_weighted(value, weight);
+        }
+    }
+}
+
+impl<T, W> FromIterator<(T, W)> for Welford<T, W>
+where
+    T: Copy + Num + NumAssign + NumCast,
+    W: Copy + Num + NumAssign + NumCast + PartialOrd,
+{
+    /// Build a calculator from an iterator of value/weight pairs.
+    ///
+    /// # Examples
+    /// ```
+    /// # use welford::Welford;
+    /// let w: Welford<f64, i32> = [(1.0, 3), (3.0, 2), (5.0, 1)].into_iter().collect();
+    /// assert_eq!(w.mean(), Some(2.333_333_333_333_333_5));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = (T, W)>>(iter: I) -> Self {
+        let mut w = Self::with_weights();
+        w.extend(iter);
+        w
+    }
+}
+
+impl<T, W> Welford<T, W>
+where
+    T: Float + NumAssign,
+    W: Copy + Num + NumAssign + NumCast + PartialOrd,
+{
+    /// Get the sample standard deviation of the samples so far.
+    ///
+    /// This is the square root of [`Welford::var`].
+    pub fn std_dev(&self) -> Option<T> {
+        self.var().map(Float::sqrt)
+    }
+
+    /// Get the population standard deviation of the samples so far.
+    ///
+    /// This is the square root of [`Welford::population_variance`].
+    pub fn population_std_dev(&self) -> Option<T> {
+        self.population_variance().map(Float::sqrt)
+    }
+
+    /// Get the standard error of the mean of the samples so far.
+    ///
+    /// This is [`Welford::std_dev`] divided by the square root of the
+    /// number of samples.
+    pub fn standard_error(&self) -> Option<T> {
+        let std_dev = self.std_dev()?;
+        let total: T = cast(self.total).expect("failed to cast W to T");
+        Some(std_dev / total.sqrt())
+    }
+
+    /// Standardize a value against the running mean and standard
+    /// deviation, i.e. compute its z-score.
+    ///
+    /// Returns `None` until [`Welford::std_dev`] is available or if it is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use welford::Welford;
+    /// let mut w = Welford::new();
+    /// w.push(1.0);
+    /// w.push(3.0);
+    /// assert_eq!(w.standardize(2.0), Some(0.0));
+    /// ```
+    pub fn standardize(&self, value: T) -> Option<T> {
+        let mean = self.mean?;
+        let std_dev = self.std_dev()?;
+
+        if std_dev.is_zero() {
+            return None;
+        }
+
+        Some((value - mean) / std_dev)
+    }
+
+    /// Get the skewness of the samples so far.
+    ///
+    /// Returns `None` until at least three samples have been accumulated.
+    /// Only meaningful if every sample was fed through [`Welford::push`],
+    /// since [`Welford::push_weighted`] does not track the higher moments
+    /// this relies on.
+    pub fn skewness(&self) -> Option<T> {
+        if self.total > W::one() + W::one() && !self.m2.is_zero() {
+            let n: T = cast(self.total).expect("failed to cast W to T");
+            let one_and_half: T = cast(1.5).expect("failed to cast to T");
+            Some(n.sqrt() * self.m3 / self.m2.powf(one_and_half))
+        } else {
+            None
+        }
+    }
+
+    /// Get the excess kurtosis of the samples so far.
+    ///
+    /// Returns `None` until at least four samples have been accumulated.
+    /// Only meaningful if every sample was fed through [`Welford::push`],
+    /// since [`Welford::push_weighted`] does not track the higher moments
+    /// this relies on.
+    pub fn kurtosis(&self) -> Option<T> {
+        if self.total > W::one() + W::one() + W::one() && !self.m2.is_zero() {
+            let n: T = cast(self.total).expect("failed to cast W to T");
+            let three: T = cast(3).expect("failed to cast to T");
+            Some(n * self.m4 / (self.m2 * self.m2) - three)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T, W> Welford<T, W>
+where
+    T: Float + NumAssign,
+    W: Copy + Num + NumAssign + NumCast + PartialOrd,
+    rand_distr::StandardNormal: Distribution<T>,
+{
+    /// Build a [`rand_distr::Normal`] matching the running mean and
+    /// standard deviation.
+    ///
+    /// Returns `None` if fewer than two samples have been accumulated or
+    /// the estimated variance is zero. The returned distribution itself
+    /// implements [`rand::distributions::Distribution`], so draws are
+    /// taken with [`rand::distributions::Distribution::sample`] on it
+    /// (there is no `Distribution` impl on `Welford` itself, since that
+    /// would have to panic instead of reporting too few samples).
+    ///
+    /// # Examples
+    /// ```
+    /// # use rand::distributions::Distribution;
+    /// # use welford::Welford;
+    /// let mut w = Welford::new();
+    /// w.push(1.0);
+    /// w.push(2.0);
+    /// let normal = w.to_normal().unwrap();
+    /// let _draw: f64 = normal.sample(&mut rand::thread_rng());
+    /// ```
+    pub fn to_normal(&self) -> Option<rand_distr::Normal<T>> {
+        let mean = self.mean?;
+        let std_dev = self.std_dev()?;
+
+        if std_dev.is_zero() {
+            return None;
+        }
+
+        rand_distr::Normal::new(mean, std_dev).ok()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,4 +630,125 @@ mod tests {
         assert_eq!(w1.mean(), Some(3.5));
         assert_eq!(w1.var(), Some(4.473_684_210_526_316));
     }
+
+    #[test]
+    fn test_summary_statistics() {
+        let mut w = Welford::new();
+        assert_eq!(w.count(), 0);
+        assert!(w.is_empty());
+        assert_eq!(w.std_dev(), None);
+        assert_eq!(w.population_variance(), None);
+        assert_eq!(w.standard_error(), None);
+
+        w.push(1.0);
+        assert_eq!(w.count(), 1);
+        assert!(!w.is_empty());
+        assert_eq!(w.population_variance(), Some(0.0));
+
+        w.push(3.0);
+        assert_eq!(w.len(), 2);
+        assert_eq!(w.sample_variance(), w.var());
+        assert_eq!(w.var(), Some(2.0));
+        assert_eq!(w.population_variance(), Some(1.0));
+        assert_eq!(w.std_dev(), Some(2.0_f64.sqrt()));
+        assert_eq!(w.population_std_dev(), Some(1.0));
+        assert_eq!(w.standard_error(), Some(2.0_f64.sqrt() / 2.0_f64.sqrt()));
+    }
+
+    #[test]
+    fn test_from_iterator() {
+        let w: Welford<f64> = [1.0, 3.0, 5.0].into_iter().collect();
+        assert_eq!(w.mean(), Some(3.0));
+        assert_eq!(w.var(), Some(4.0));
+
+        let w: Welford<f64, i32> = [(1.0, 3), (3.0, 2), (5.0, 1)].into_iter().collect();
+        assert_eq!(w.mean(), Some(2.333_333_333_333_333_5));
+        assert_eq!(w.var(), Some(2.666_666_666_666_666_5));
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut w = Welford::new();
+        w.push(1.0);
+        w.extend([3.0, 5.0]);
+        assert_eq!(w.mean(), Some(3.0));
+        assert_eq!(w.var(), Some(4.0));
+
+        let mut w = Welford::with_weights();
+        w.extend([(1.0, 3), (3.0, 2), (5.0, 1)]);
+        assert_eq!(w.mean(), Some(2.333_333_333_333_333_5));
+        assert_eq!(w.var(), Some(2.666_666_666_666_666_5));
+    }
+
+    #[test]
+    fn test_skewness_and_kurtosis() {
+        let mut w = Welford::new();
+        assert_eq!(w.skewness(), None);
+        assert_eq!(w.kurtosis(), None);
+
+        for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            w.push(value);
+        }
+
+        assert_eq!(w.skewness(), Some(0.656_250_000_000_000_2));
+        assert_eq!(w.kurtosis(), Some(-0.218_75));
+    }
+
+    #[test]
+    fn test_merged_skewness_and_kurtosis() {
+        let mut w1 = Welford::new();
+        let mut w2 = Welford::new();
+
+        for value in [2.0, 4.0, 4.0, 4.0] {
+            w1.push(value);
+        }
+        for value in [5.0, 5.0, 7.0, 9.0] {
+            w2.push(value);
+        }
+
+        w1.merge(w2);
+        assert_eq!(w1.skewness(), Some(0.656_249_999_999_999_8));
+        assert_eq!(w1.kurtosis(), Some(-0.218_750_000_000_000_9));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_to_normal() {
+        use rand::distributions::Distribution;
+
+        let mut w = Welford::new();
+        assert!(w.to_normal().is_none());
+
+        w.push(1.0);
+        assert!(w.to_normal().is_none());
+
+        w.push(3.0);
+        let normal = w.to_normal().unwrap();
+        assert_eq!(normal.mean(), 2.0);
+        assert_eq!(normal.std_dev(), 2.0_f64.sqrt());
+
+        let mut rng = rand::rngs::mock::StepRng::new(0, 1);
+        let _draw: f64 = normal.sample(&mut rng);
+    }
+
+    #[test]
+    fn test_standardize() {
+        let mut w = Welford::new();
+        assert_eq!(w.standardize(1.0), None);
+
+        w.push(1.0);
+        assert_eq!(w.standardize(1.0), None);
+
+        w.push(3.0);
+        assert_eq!(w.standardize(2.0), Some(0.0));
+        assert_eq!(w.standardize(3.0), Some(1.0 / 2.0_f64.sqrt()));
+    }
+
+    #[test]
+    fn test_push_standardized() {
+        let mut w = Welford::new();
+        assert_eq!(w.push_standardized(1.0), None);
+        assert_eq!(w.push_standardized(3.0), Some(1.0 / 2.0_f64.sqrt()));
+        assert_eq!(w.mean(), Some(2.0));
+    }
 }